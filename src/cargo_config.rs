@@ -0,0 +1,45 @@
+use crate::{common, contract_package, Registry, ARGS};
+
+const DIRNAME: &str = ".cargo";
+const FILENAME: &str = "config.toml";
+const CONTRACT_CONTENTS: &str = r#"[build]
+target = "wasm32-unknown-unknown"
+
+[alias]
+build-release = "build --release"
+"#;
+
+/// Writes a `.cargo/config.toml` into the contract package's directory (not the workspace root),
+/// so the wasm build target only applies when building the contract and not the native tests
+/// package. When `--registry`/`--registry-url` were given, also writes a `[registries.<name>]`
+/// entry into the project root's `.cargo/config.toml`, since both `contract/` and `tests/` need to
+/// see it: cargo resolves registry config by walking up from the package being built, so a
+/// registry defined only under `contract/` would be invisible to `cargo test` run from `tests/`.
+pub fn create() {
+    let contract_cargo_dir = ARGS
+        .root_path()
+        .join(contract_package::DIRNAME)
+        .join(DIRNAME);
+    common::create_dir_all(&contract_cargo_dir);
+    common::write_file(contract_cargo_dir.join(FILENAME), CONTRACT_CONTENTS);
+
+    if let Some(registry) = ARGS.registry() {
+        let root_cargo_dir = ARGS.root_path().join(DIRNAME);
+        common::create_dir_all(&root_cargo_dir);
+        common::write_file(root_cargo_dir.join(FILENAME), registry_contents(registry));
+    }
+}
+
+/// Delegates authentication to an external credential-provider binary (e.g. a 1Password
+/// integration) rather than the built-in `cargo:token` provider, which stores the token in
+/// plaintext.
+fn registry_contents(registry: &Registry) -> String {
+    format!(
+        r#"[registries.{name}]
+index = "{url}"
+credential-provider = ["cargo-credential-1password"]
+"#,
+        name = registry.name(),
+        url = registry.url(),
+    )
+}
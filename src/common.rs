@@ -3,36 +3,104 @@ use std::{fs, path::Path, process, str};
 use colour::e_red;
 use once_cell::sync::Lazy;
 
-use crate::{dependency::Dependency, DimensionOverrides, ARGS, FAILURE_EXIT_CODE};
-
-pub static CL_CONTRACT: Lazy<Dependency> =
-    Lazy::new(|| Dependency::new("dimension-contract", "1.4.3"));
-pub static CL_TYPES: Lazy<Dependency> = Lazy::new(|| Dependency::new("dimension-types", "1.4.6"));
-pub static CL_ENGINE_TEST_SUPPORT: Lazy<Dependency> =
-    Lazy::new(|| Dependency::new("dimension-engine-test-support", "2.0.3"));
-pub static CL_EXECUTION_ENGINE: Lazy<Dependency> =
-    Lazy::new(|| Dependency::new("dimension-execution-engine", "1.4.4"));
+use crate::{
+    dependency::Dependency, DimensionOverrides, Registry, ReplaceSource, ARGS, FAILURE_EXIT_CODE,
+};
+
+pub static CL_CONTRACT: Lazy<Dependency> = Lazy::new(|| {
+    maybe_resolve_latest(Dependency::new("dimension-contract", "1.4.3"))
+});
+pub static CL_TYPES: Lazy<Dependency> =
+    Lazy::new(|| maybe_resolve_latest(Dependency::new("dimension-types", "1.4.6")));
+pub static CL_ENGINE_TEST_SUPPORT: Lazy<Dependency> = Lazy::new(|| {
+    maybe_resolve_latest(Dependency::new("dimension-engine-test-support", "2.0.3"))
+});
+pub static CL_EXECUTION_ENGINE: Lazy<Dependency> = Lazy::new(|| {
+    maybe_resolve_latest(Dependency::new("dimension-execution-engine", "1.4.4"))
+});
+
+/// If `--latest` was passed, replaces `dep`'s hard-coded version with the newest one published
+/// to crates.io; otherwise returns `dep` unchanged.  Unit tests always get the hard-coded version,
+/// and `ARGS` is not parsed for them, since the test binary's own args aren't valid `Args`.
+fn maybe_resolve_latest(dep: Dependency) -> Dependency {
+    if !cfg!(test) && ARGS.latest() {
+        dep.resolve_latest()
+    } else {
+        dep
+    }
+}
+/// Name of the `[patch.<name>]` table the dimension crates are patched under: the configured
+/// registry's name if one was given via `--registry`, otherwise `crates-io`.
+fn patch_registry_name() -> &'static str {
+    ARGS.registry().map_or("crates-io", Registry::name)
+}
+
+/// Returns the TOML value for a dimension-* crate's dependency line, sourcing it from the
+/// configured `--registry` instead of crates.io when one is set.
+pub fn dependency_toml_value(dep: &Dependency) -> String {
+    match ARGS.registry() {
+        Some(registry) => format!(
+            r#"{{ version = "{}", registry = "{}" }}"#,
+            dep.version(),
+            registry.name()
+        ),
+        None => format!(r#""{}""#, dep.version()),
+    }
+}
+
 pub static PATCH_SECTION: Lazy<String> = Lazy::new(|| match ARGS.dimension_overrides() {
     Some(DimensionOverrides::WorkspacePath(path)) => {
         format!(
-            r#"[patch.crates-io]
+            r#"[patch.{1}]
 dimension-contract = {{ path = "{0}/smart_contracts/contract" }}
 dimension-engine-test-support = {{ path = "{0}/execution_engine_testing/test_support" }}
 dimension-execution-engine = {{ path = "{0}/execution_engine" }}
 dimension-types = {{ path = "{0}/types" }}
 "#,
-            path.display()
+            path.display(),
+            patch_registry_name()
         )
     }
     Some(DimensionOverrides::GitRepo { url, branch }) => {
         format!(
-            r#"[patch.crates-io]
+            r#"[patch.{2}]
 dimension-contract = {{ git = "{0}", branch = "{1}" }}
 dimension-engine-test-support = {{ git = "{0}", branch = "{1}" }}
 dimension-execution-engine = {{ git = "{0}", branch = "{1}" }}
 dimension-types = {{ git = "{0}", branch = "{1}" }}
 "#,
-            url, branch
+            url,
+            branch,
+            patch_registry_name()
+        )
+    }
+    Some(DimensionOverrides::Replace {
+        version,
+        source: ReplaceSource::WorkspacePath(path),
+    }) => {
+        format!(
+            r#"[replace]
+"dimension-contract:{1}" = {{ path = "{0}/smart_contracts/contract" }}
+"dimension-engine-test-support:{1}" = {{ path = "{0}/execution_engine_testing/test_support" }}
+"dimension-execution-engine:{1}" = {{ path = "{0}/execution_engine" }}
+"dimension-types:{1}" = {{ path = "{0}/types" }}
+"#,
+            path.display(),
+            version
+        )
+    }
+    Some(DimensionOverrides::Replace {
+        version,
+        source: ReplaceSource::GitRepo { url, branch },
+    }) => {
+        format!(
+            r#"[replace]
+"dimension-contract:{2}" = {{ git = "{0}", branch = "{1}" }}
+"dimension-engine-test-support:{2}" = {{ git = "{0}", branch = "{1}" }}
+"dimension-execution-engine:{2}" = {{ git = "{0}", branch = "{1}" }}
+"dimension-types:{2}" = {{ git = "{0}", branch = "{1}" }}
+"#,
+            url, branch, version
         )
     }
     None => String::new(),
@@ -0,0 +1,45 @@
+use crate::{
+    common::{self, dependency_toml_value, CL_CONTRACT, CL_TYPES, PATCH_SECTION},
+    ARGS,
+};
+
+pub(crate) const DIRNAME: &str = "contract";
+const CARGO_TOML_FILENAME: &str = "Cargo.toml";
+const MAIN_RS_CONTENTS: &str = include_str!("../resources/contract-main.rs.in");
+
+pub fn create() {
+    let dir = ARGS.root_path().join(DIRNAME);
+    common::create_dir_all(dir.join("src"));
+    common::write_file(dir.join("src").join("main.rs"), MAIN_RS_CONTENTS);
+    common::write_file(dir.join(CARGO_TOML_FILENAME), cargo_toml());
+}
+
+fn cargo_toml() -> String {
+    format!(
+        r#"[package]
+name = "contract"
+version = "0.1.0"
+edition = "2021"
+
+[[bin]]
+name = "contract"
+bench = false
+doctest = false
+test = false
+
+[dependencies]
+{contract} = {contract_value}
+{types} = {types_value}
+
+[profile.release]
+codegen-units = 1
+lto = true
+
+{patch}"#,
+        contract = CL_CONTRACT.name(),
+        contract_value = dependency_toml_value(&*CL_CONTRACT),
+        types = CL_TYPES.name(),
+        types_value = dependency_toml_value(&*CL_TYPES),
+        patch = *PATCH_SECTION,
+    )
+}
@@ -0,0 +1,72 @@
+//! A dependency on a published `dimension-*` crate, along with support for resolving the
+//! newest version actually published to crates.io.
+
+use colour::e_yellow;
+use reqwest::blocking;
+use serde_json::Value;
+
+const SPARSE_INDEX_BASE_URL: &str = "https://index.crates.io";
+const VERSION_FIELD_NAME: &str = "vers";
+const YANKED_FIELD_NAME: &str = "yanked";
+
+#[derive(Debug, Clone)]
+pub struct Dependency {
+    name: String,
+    version: String,
+}
+
+impl Dependency {
+    pub fn new(name: &str, version: &str) -> Self {
+        Dependency {
+            name: name.to_string(),
+            version: version.to_string(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns a copy of this dependency with its version replaced by the newest non-yanked
+    /// version published to crates.io, as read from the sparse index.  Falls back to returning
+    /// an unmodified clone if the index can't be reached or parsed, e.g. when offline.
+    pub fn resolve_latest(&self) -> Dependency {
+        match fetch_latest_version(&self.name) {
+            Some(version) => Dependency::new(&self.name, &version),
+            None => {
+                e_yellow!("warning");
+                eprintln!(
+                    ": couldn't resolve latest version of '{}' from the crates.io index; \
+                    falling back to the hard-coded version {}",
+                    self.name, self.version
+                );
+                self.clone()
+            }
+        }
+    }
+}
+
+/// Builds the sparse-index path for a crate name, following crates.io's convention for names of
+/// four or more characters: `<first two chars>/<next two chars>/<name>`.
+fn sparse_index_path(name: &str) -> String {
+    format!("{}/{}/{}", &name[0..2], &name[2..4], name)
+}
+
+/// Fetches the newest non-yanked version of `name` from the crates.io sparse index.  The index
+/// file is newline-delimited JSON, one published release per line; returns `None` if the file
+/// can't be fetched or parsed, or if every entry is yanked.
+fn fetch_latest_version(name: &str) -> Option<String> {
+    let url = format!("{}/{}", SPARSE_INDEX_BASE_URL, sparse_index_path(name));
+    let index_contents = blocking::get(url).ok()?.text().ok()?;
+
+    index_contents
+        .lines()
+        .rev()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .find(|entry| !entry[YANKED_FIELD_NAME].as_bool().unwrap_or(false))
+        .and_then(|entry| entry[VERSION_FIELD_NAME].as_str().map(str::to_string))
+}
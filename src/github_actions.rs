@@ -0,0 +1,13 @@
+use crate::{common, rust_toolchain, ARGS};
+
+const DIRNAME: &str = ".github/workflows";
+const FILENAME: &str = "ci.yml";
+const TEMPLATE: &str = include_str!("../resources/github-actions-ci.yml.in");
+const RUST_TOOLCHAIN_PLACEHOLDER: &str = "{{RUST_TOOLCHAIN}}";
+
+pub fn create() {
+    let contents = TEMPLATE.replace(RUST_TOOLCHAIN_PLACEHOLDER, rust_toolchain::CONTENTS.trim());
+    let dir = ARGS.root_path().join(DIRNAME);
+    common::create_dir_all(&dir);
+    common::write_file(dir.join(FILENAME), contents);
+}
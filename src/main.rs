@@ -2,9 +2,11 @@
 
 #![deny(warnings)]
 
+mod cargo_config;
 pub mod common;
 mod contract_package;
 pub mod dependency;
+mod github_actions;
 mod makefile;
 mod rust_toolchain;
 mod tests_package;
@@ -15,7 +17,10 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use clap::{builder::ValueParser, crate_description, crate_name, crate_version, Arg, Command};
+use clap::{
+    builder::ValueParser, crate_description, crate_name, crate_version, Arg, ArgAction, ArgGroup,
+    Command,
+};
 use once_cell::sync::Lazy;
 
 const USAGE: &str = r#"cargo dimension [FLAGS] <path>
@@ -36,24 +41,111 @@ const GIT_URL_LONG: &str = "git-url";
 const GIT_BRANCH_ARG_NAME: &str = "git-branch";
 const GIT_BRANCH_LONG: &str = "git-branch";
 
+const REPLACE_VERSION_ARG_NAME: &str = "replace-version";
+const REPLACE_VERSION_LONG: &str = "replace-version";
+
+/// Group of args, one of which must be present for a `DimensionOverrides` source to be specified.
+const DIMENSION_SOURCE_GROUP: &str = "dimension-source";
+
+const REGISTRY_ARG_NAME: &str = "registry";
+const REGISTRY_LONG: &str = "registry";
+const REGISTRY_HELP: &str = "Name of an alternate registry to source the dimension-* crates from";
+
+const REGISTRY_URL_ARG_NAME: &str = "registry-url";
+const REGISTRY_URL_LONG: &str = "registry-url";
+const REGISTRY_URL_HELP: &str = "URL of the alternate registry named by --registry";
+
+const LATEST_ARG_NAME: &str = "latest";
+const LATEST_ARG_LONG: &str = "latest";
+const LATEST_ARG_HELP: &str = "Resolve the newest published versions of the dimension-* crates \
+    from the crates.io index instead of using the hard-coded versions";
+
+const WITH_INTEGRATION_ARG_NAME: &str = "with-integration";
+const WITH_INTEGRATION_ARG_LONG: &str = "with-integration";
+const WITH_INTEGRATION_ARG_HELP: &str =
+    "Also scaffold a Docker-based integration test harness that runs against a local Dimension \
+    node container";
+
+const CI_ARG_NAME: &str = "ci";
+const CI_ARG_LONG: &str = "ci";
+const CI_ARG_HELP: &str = "Selects which CI config to scaffold";
+const CI_ARG_GITHUB: &str = "github";
+const CI_ARG_TRAVIS: &str = "travis";
+const CI_ARG_NONE: &str = "none";
+
 const FAILURE_EXIT_CODE: i32 = 101;
 
 static ARGS: Lazy<Args> = Lazy::new(Args::new);
 
-/// Can be used (via hidden command line args) to specify a patch section for the dimension crates in
-/// the generated Cargo.toml files.
+/// Selects which CI config (if any) should be scaffolded into the generated project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CiBackend {
+    GitHub,
+    Travis,
+    None,
+}
+
+impl CiBackend {
+    fn parse(value: &str) -> Self {
+        match value {
+            CI_ARG_GITHUB => CiBackend::GitHub,
+            CI_ARG_TRAVIS => CiBackend::Travis,
+            CI_ARG_NONE => CiBackend::None,
+            _ => unreachable!("clap should only allow 'github', 'travis' or 'none'"),
+        }
+    }
+}
+
+/// Can be used (via hidden command line args) to specify a patch or replace section for the
+/// dimension crates in the generated Cargo.toml files.
 #[derive(Debug)]
 enum DimensionOverrides {
     /// The path to local copy of the dimension-node repository.
     WorkspacePath(PathBuf),
     /// The details of an online copy of the dimension-node repository.
     GitRepo { url: String, branch: String },
+    /// Pins a `[replace]` override of the dimension crates at `version` to the given source,
+    /// rather than a `[patch.crates-io]` override. Useful when a transitive dependency locked to
+    /// a specific version, not the direct one, needs swapping.
+    Replace {
+        version: String,
+        source: ReplaceSource,
+    },
+}
+
+/// The source a `DimensionOverrides::Replace` entry resolves to.
+#[derive(Debug)]
+enum ReplaceSource {
+    WorkspacePath(PathBuf),
+    GitRepo { url: String, branch: String },
+}
+
+/// An alternate registry the generated Cargo.toml files should source the dimension-* crates
+/// from, in place of crates.io.
+#[derive(Debug)]
+struct Registry {
+    name: String,
+    url: String,
+}
+
+impl Registry {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
 }
 
 #[derive(Debug)]
 struct Args {
     root_path: PathBuf,
     dimension_overrides: Option<DimensionOverrides>,
+    latest: bool,
+    ci_backend: CiBackend,
+    with_integration: bool,
+    registry: Option<Registry>,
 }
 
 impl Args {
@@ -100,6 +192,41 @@ impl Args {
             .conflicts_with(WORKSPACE_PATH_ARG_NAME)
             .requires(GIT_URL_ARG_NAME);
 
+        let replace_version_arg = Arg::new(REPLACE_VERSION_ARG_NAME)
+            .long(REPLACE_VERSION_LONG)
+            .takes_value(true)
+            .hide(true)
+            .requires(DIMENSION_SOURCE_GROUP);
+
+        let registry_arg = Arg::new(REGISTRY_ARG_NAME)
+            .long(REGISTRY_LONG)
+            .takes_value(true)
+            .requires(REGISTRY_URL_ARG_NAME)
+            .help(REGISTRY_HELP);
+
+        let registry_url_arg = Arg::new(REGISTRY_URL_ARG_NAME)
+            .long(REGISTRY_URL_LONG)
+            .takes_value(true)
+            .requires(REGISTRY_ARG_NAME)
+            .help(REGISTRY_URL_HELP);
+
+        let latest_arg = Arg::new(LATEST_ARG_NAME)
+            .long(LATEST_ARG_LONG)
+            .action(ArgAction::SetTrue)
+            .help(LATEST_ARG_HELP);
+
+        let with_integration_arg = Arg::new(WITH_INTEGRATION_ARG_NAME)
+            .long(WITH_INTEGRATION_ARG_LONG)
+            .action(ArgAction::SetTrue)
+            .help(WITH_INTEGRATION_ARG_HELP);
+
+        let ci_arg = Arg::new(CI_ARG_NAME)
+            .long(CI_ARG_LONG)
+            .takes_value(true)
+            .value_parser([CI_ARG_GITHUB, CI_ARG_TRAVIS, CI_ARG_NONE])
+            .default_value(CI_ARG_GITHUB)
+            .help(CI_ARG_HELP);
+
         let arg_matches = Command::new(crate_name!())
             .version(crate_version!())
             .about(crate_description!())
@@ -108,6 +235,16 @@ impl Args {
             .arg(workspace_path_arg)
             .arg(git_url_arg)
             .arg(git_branch_arg)
+            .arg(replace_version_arg)
+            .arg(registry_arg)
+            .arg(registry_url_arg)
+            .arg(latest_arg)
+            .arg(with_integration_arg)
+            .arg(ci_arg)
+            .group(
+                ArgGroup::new(DIMENSION_SOURCE_GROUP)
+                    .args([WORKSPACE_PATH_ARG_NAME, GIT_URL_ARG_NAME]),
+            )
             .get_matches_from(filtered_args_iter);
 
         let root_path = arg_matches
@@ -115,23 +252,68 @@ impl Args {
             .expect("expected path")
             .clone();
 
+        let latest = arg_matches.get_flag(LATEST_ARG_NAME);
+        let with_integration = arg_matches.get_flag(WITH_INTEGRATION_ARG_NAME);
+
+        let ci_backend = CiBackend::parse(
+            arg_matches
+                .get_one::<String>(CI_ARG_NAME)
+                .expect("should have default ci value"),
+        );
+
         let maybe_workspace_path = arg_matches.get_one::<String>(WORKSPACE_PATH_ARG_NAME);
         let maybe_git_url = arg_matches.get_one::<String>(GIT_URL_ARG_NAME);
         let maybe_git_branch = arg_matches.get_one::<String>(GIT_BRANCH_ARG_NAME);
+        let maybe_replace_version = arg_matches.get_one::<String>(REPLACE_VERSION_ARG_NAME);
 
-        let dimension_overrides = match (maybe_workspace_path, maybe_git_url, maybe_git_branch) {
-            (Some(path), None, None) => Some(DimensionOverrides::WorkspacePath(path.into())),
-            (None, Some(url), Some(branch)) => Some(DimensionOverrides::GitRepo {
+        let maybe_registry_name = arg_matches.get_one::<String>(REGISTRY_ARG_NAME);
+        let maybe_registry_url = arg_matches.get_one::<String>(REGISTRY_URL_ARG_NAME);
+        let registry = match (maybe_registry_name, maybe_registry_url) {
+            (Some(name), Some(url)) => Some(Registry {
+                name: name.to_string(),
+                url: url.to_string(),
+            }),
+            (None, None) => None,
+            _ => unreachable!("Clap rules enforce either both or neither registry args are present"),
+        };
+
+        let dimension_overrides = match (
+            maybe_workspace_path,
+            maybe_git_url,
+            maybe_git_branch,
+            maybe_replace_version,
+        ) {
+            (Some(path), None, None, None) => Some(DimensionOverrides::WorkspacePath(path.into())),
+            (None, Some(url), Some(branch), None) => Some(DimensionOverrides::GitRepo {
                 url: url.to_string(),
                 branch: branch.to_string(),
             }),
-            (None, None, None) => None,
-            _ => unreachable!("Clap rules enforce either both or neither git args are present"),
+            (Some(path), None, None, Some(version)) => Some(DimensionOverrides::Replace {
+                version: version.to_string(),
+                source: ReplaceSource::WorkspacePath(path.into()),
+            }),
+            (None, Some(url), Some(branch), Some(version)) => Some(DimensionOverrides::Replace {
+                version: version.to_string(),
+                source: ReplaceSource::GitRepo {
+                    url: url.to_string(),
+                    branch: branch.to_string(),
+                },
+            }),
+            (None, None, None, None) => None,
+            _ => unreachable!(
+                "clap rules enforce that --git-url/--git-branch are given together, that \
+                --workspace-path and --git-url are mutually exclusive, and that \
+                --replace-version requires --workspace-path or --git-url"
+            ),
         };
 
         Args {
             root_path,
             dimension_overrides,
+            latest,
+            ci_backend,
+            with_integration,
+            registry,
         }
     }
 
@@ -142,6 +324,22 @@ impl Args {
     pub fn dimension_overrides(&self) -> Option<&DimensionOverrides> {
         self.dimension_overrides.as_ref()
     }
+
+    pub fn latest(&self) -> bool {
+        self.latest
+    }
+
+    fn ci_backend(&self) -> CiBackend {
+        self.ci_backend
+    }
+
+    pub fn with_integration(&self) -> bool {
+        self.with_integration
+    }
+
+    pub fn registry(&self) -> Option<&Registry> {
+        self.registry.as_ref()
+    }
 }
 
 fn main() {
@@ -154,8 +352,13 @@ fn main() {
 
     common::create_dir_all(ARGS.root_path());
     contract_package::create();
+    cargo_config::create();
     tests_package::create();
     rust_toolchain::create();
     makefile::create();
-    travis_yml::create();
+    match ARGS.ci_backend() {
+        CiBackend::GitHub => github_actions::create(),
+        CiBackend::Travis => travis_yml::create(),
+        CiBackend::None => {}
+    }
 }
@@ -0,0 +1,8 @@
+use crate::{common, ARGS};
+
+const FILENAME: &str = "Makefile";
+const CONTENTS: &str = include_str!("../resources/Makefile.in");
+
+pub fn create() {
+    common::write_file(ARGS.root_path().join(FILENAME), CONTENTS);
+}
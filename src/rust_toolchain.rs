@@ -1,7 +1,7 @@
 use crate::{common, ARGS};
 
 const FILENAME: &str = "rust-toolchain";
-const CONTENTS: &str = include_str!("../resources/rust-toolchain.in");
+pub(crate) const CONTENTS: &str = include_str!("../resources/rust-toolchain.in");
 
 pub fn create() {
     common::write_file(ARGS.root_path().join(FILENAME), CONTENTS);
@@ -0,0 +1,75 @@
+use crate::{
+    common::{
+        self, dependency_toml_value, CL_ENGINE_TEST_SUPPORT, CL_EXECUTION_ENGINE, CL_TYPES,
+        PATCH_SECTION,
+    },
+    ARGS,
+};
+
+pub(crate) const DIRNAME: &str = "tests";
+const CARGO_TOML_FILENAME: &str = "Cargo.toml";
+const INTEGRATION_TESTS_RS_CONTENTS: &str = include_str!("../resources/integration-tests.rs.in");
+
+const DOCKER_COMPOSE_FILENAME: &str = "docker-compose.yml";
+const DOCKER_COMPOSE_CONTENTS: &str = include_str!("../resources/docker-compose.yml.in");
+const COMMON_MOD_RS_CONTENTS: &str = include_str!("../resources/common-mod.rs.in");
+const CONTAINERS_RS_CONTENTS: &str = include_str!("../resources/containers.rs.in");
+const NODE_INTEGRATION_TESTS_RS_CONTENTS: &str =
+    include_str!("../resources/node-integration-tests.rs.in");
+
+pub fn create() {
+    let dir = ARGS.root_path().join(DIRNAME);
+    common::create_dir_all(dir.join("tests"));
+    common::write_file(
+        dir.join("tests").join("integration_tests.rs"),
+        INTEGRATION_TESTS_RS_CONTENTS,
+    );
+    common::write_file(dir.join(CARGO_TOML_FILENAME), cargo_toml());
+
+    if ARGS.with_integration() {
+        create_integration_harness(&dir);
+    }
+}
+
+/// Scaffolds a Docker-based integration harness: a `docker-compose.yml` that spins up a local
+/// Dimension node container, a `common::containers` fixture module the generated tests crate can
+/// use to start it, wait for readiness, and tear it down, and an integration test that actually
+/// exercises it.
+fn create_integration_harness(tests_dir: &std::path::Path) {
+    common::write_file(tests_dir.join(DOCKER_COMPOSE_FILENAME), DOCKER_COMPOSE_CONTENTS);
+
+    let common_dir = tests_dir.join("tests").join("common");
+    common::create_dir_all(&common_dir);
+    common::write_file(common_dir.join("mod.rs"), COMMON_MOD_RS_CONTENTS);
+    common::write_file(common_dir.join("containers.rs"), CONTAINERS_RS_CONTENTS);
+
+    common::write_file(
+        tests_dir.join("tests").join("node_integration_tests.rs"),
+        NODE_INTEGRATION_TESTS_RS_CONTENTS,
+    );
+}
+
+fn cargo_toml() -> String {
+    format!(
+        r#"[package]
+name = "tests"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+{types} = {types_value}
+
+[dev-dependencies]
+{engine_test_support} = {engine_test_support_value}
+{execution_engine} = {execution_engine_value}
+
+{patch}"#,
+        types = CL_TYPES.name(),
+        types_value = dependency_toml_value(&*CL_TYPES),
+        engine_test_support = CL_ENGINE_TEST_SUPPORT.name(),
+        engine_test_support_value = dependency_toml_value(&*CL_ENGINE_TEST_SUPPORT),
+        execution_engine = CL_EXECUTION_ENGINE.name(),
+        execution_engine_value = dependency_toml_value(&*CL_EXECUTION_ENGINE),
+        patch = *PATCH_SECTION,
+    )
+}
@@ -0,0 +1,8 @@
+use crate::{common, ARGS};
+
+const FILENAME: &str = ".travis.yml";
+const CONTENTS: &str = include_str!("../resources/travis.yml.in");
+
+pub fn create() {
+    common::write_file(ARGS.root_path().join(FILENAME), CONTENTS);
+}